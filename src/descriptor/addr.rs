@@ -11,7 +11,7 @@ use core::{fmt, str::FromStr};
 
 use bitcoin::{
     util::address::{Payload, WitnessVersion},
-    Address, AddressType, Script,
+    Address, AddressType, Network, Script,
 };
 
 use crate::{
@@ -21,6 +21,20 @@ use crate::{
 
 use super::checksum::{desc_checksum, verify_checksum};
 
+/// Classifies how an address relates to segwit, mirroring the distinction
+/// `bitcoin_scripts`' `SegWitInfo` makes: a P2PKH output predates segwit entirely, a P2SH
+/// output is ambiguous since it may or may not wrap a witness program, and a witness
+/// program output is explicit about its version.
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum SegWitInfo {
+    /// A pre-segwit output (P2PKH)
+    PreSegWit,
+    /// A P2SH output, which may or may not wrap a segwit witness program
+    Ambiguous,
+    /// An explicit witness program output of the given version (P2WPKH, P2WSH, P2TR, ...)
+    SegWit(WitnessVersion),
+}
+
 /// A standalone address descriptor
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct Addr {
@@ -35,6 +49,24 @@ impl Addr {
         Addr { address }
     }
 
+    /// Parses an `addr(ADDR)` descriptor, rejecting it if the address was not issued for
+    /// `expected`.
+    ///
+    /// Unlike [`Addr::from_str`], which accepts an address for any network, this checks the
+    /// decoded address against `expected` and returns [`Error::AddrNetworkMismatch`] on a
+    /// mismatch, preventing e.g. a testnet address from being silently used in a mainnet
+    /// descriptor.
+    pub fn from_str_checked(s: &str, expected: Network) -> Result<Self, Error> {
+        let addr = Addr::from_str(s)?;
+        if addr.network() != expected {
+            return Err(Error::AddrNetworkMismatch {
+                expected,
+                found: addr.network(),
+            });
+        }
+        Ok(addr)
+    }
+
     /// Get the inner address
     pub fn into_inner(self) -> Address {
         self.address
@@ -65,6 +97,11 @@ impl Addr {
         self.address.clone()
     }
 
+    /// Get the network the inner address was parsed for
+    pub fn network(&self) -> Network {
+        self.address.network
+    }
+
     /// Obtains the segwit version for the contained address
     pub fn segwit_version(&self) -> Option<WitnessVersion> {
         match self.address.payload {
@@ -73,6 +110,32 @@ impl Addr {
         }
     }
 
+    /// Classifies the contained address's relationship to segwit.
+    ///
+    /// Unlike [`Addr::segwit_version`], which only recognizes explicit witness programs,
+    /// this also distinguishes the pre-segwit P2PKH case from the ambiguous P2SH case (which
+    /// may be a nested-segwit wrapper), letting callers reason about the spend type without
+    /// re-matching on `Payload` themselves.
+    pub fn segwit_info(&self) -> SegWitInfo {
+        match self.address.payload {
+            Payload::PubkeyHash(_) => SegWitInfo::PreSegWit,
+            Payload::ScriptHash(_) => SegWitInfo::Ambiguous,
+            Payload::WitnessProgram { version, .. } => SegWitInfo::SegWit(version),
+        }
+    }
+
+    /// Reports the byte length of this address's scriptPubKey, for fee estimation.
+    ///
+    /// For a P2SH address this is the size of the P2SH scriptPubKey itself (23 bytes), not
+    /// the redeem script it may wrap, since that script is not known from the address alone.
+    pub fn spk_size_hint(&self) -> usize {
+        match self.address.payload {
+            Payload::PubkeyHash(_) => 25,
+            Payload::ScriptHash(_) => 23,
+            Payload::WitnessProgram { ref program, .. } => 2 + program.len(),
+        }
+    }
+
     /// Obtains the explicit script for the inner address
     pub fn explicit_script(&self) -> Result<Script, Error> {
         match self.address.address_type() {
@@ -145,3 +208,60 @@ where
         Ok(Addr::new(self.address.clone()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_checked_rejects_network_mismatch() {
+        let testnet_addr =
+            Address::from_str("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx").unwrap();
+        let s = Addr::new(testnet_addr).to_string();
+
+        let err = Addr::from_str_checked(&s, Network::Bitcoin).unwrap_err();
+        match err {
+            Error::AddrNetworkMismatch { expected, found } => {
+                assert_eq!(expected, Network::Bitcoin);
+                assert_eq!(found, Network::Testnet);
+            }
+            other => panic!("expected AddrNetworkMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn from_str_checked_accepts_matching_network() {
+        let mainnet_addr =
+            Address::from_str("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").unwrap();
+        let s = Addr::new(mainnet_addr.clone()).to_string();
+
+        let addr = Addr::from_str_checked(&s, Network::Bitcoin).unwrap();
+        assert_eq!(addr.address(), mainnet_addr);
+    }
+
+    #[test]
+    fn spk_size_hint_matches_real_script_pubkey_p2pkh() {
+        let addr = Addr::new(Address::from_str("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").unwrap());
+        assert_eq!(addr.spk_size_hint(), addr.script_pubkey().len());
+        assert_eq!(addr.segwit_info(), SegWitInfo::PreSegWit);
+    }
+
+    #[test]
+    fn spk_size_hint_matches_real_script_pubkey_p2sh() {
+        let addr = Addr::new(Address::from_str("3P14159f73E4gFr7JterCCQh9QjiTjiZrG").unwrap());
+        assert_eq!(addr.spk_size_hint(), addr.script_pubkey().len());
+        assert_eq!(addr.segwit_info(), SegWitInfo::Ambiguous);
+    }
+
+    #[test]
+    fn spk_size_hint_matches_real_script_pubkey_witness_program() {
+        let addr = Addr::new(
+            Address::from_str("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").unwrap(),
+        );
+        assert_eq!(addr.spk_size_hint(), addr.script_pubkey().len());
+        match addr.segwit_info() {
+            SegWitInfo::SegWit(version) => assert_eq!(version, WitnessVersion::V0),
+            other => panic!("expected SegWit variant, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! # Output Descriptors
+//!
+//! This ties the individual descriptor types (`addr()`, `raw()`, ...) together into the
+//! top-level [`Descriptor`] type that callers parse arbitrary Bitcoin Core descriptor
+//! strings into, and through which `addr(ADDR)`/`raw(HEX)` round-trip.
+//!
+//! Note: this source tree currently only carries the key-less descriptor types (`addr()`
+//! and `raw()`); the key-bearing templates (`pk()`, `pkh()`, `wpkh()`, `sh()`, `wsh()`,
+//! `tr()`, ...) and their backing modules are not part of this slice of the crate, so
+//! `Descriptor` does not yet carry a `Pk: MiniscriptKey` parameter here. Once those modules
+//! land, `Descriptor` gains that parameter and their variants alongside `Addr`/`Raw`.
+//!
+
+pub mod addr;
+mod checksum;
+pub mod raw;
+
+use core::fmt;
+use core::str::FromStr;
+
+use bitcoin::{Address, Script};
+
+use crate::{
+    expression::{self, FromTree},
+    Error, MiniscriptKey, TranslatePk,
+};
+
+pub use self::addr::{Addr, SegWitInfo};
+pub use self::raw::Raw;
+
+/// A parsed output descriptor.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Descriptor {
+    /// A standalone address descriptor: `addr(ADDR)`
+    Addr(Addr),
+    /// A raw scriptPubKey descriptor: `raw(HEX)`
+    Raw(Raw),
+}
+
+impl Descriptor {
+    /// Checks whether the descriptor is safe.
+    pub fn sanity_check(&self) -> Result<(), Error> {
+        match self {
+            Descriptor::Addr(addr) => addr.sanity_check(),
+            Descriptor::Raw(raw) => raw.sanity_check(),
+        }
+    }
+
+    /// Obtains the corresponding script pubkey for this descriptor.
+    pub fn script_pubkey(&self) -> Script {
+        match self {
+            Descriptor::Addr(addr) => addr.script_pubkey(),
+            Descriptor::Raw(raw) => raw.script_pubkey(),
+        }
+    }
+
+    /// Obtains the Bitcoin address for this descriptor, if it has one.
+    ///
+    /// Only an `Addr` variant resolves to an address; a `raw()` descriptor's scriptPubKey
+    /// may not correspond to any address format.
+    pub fn address(&self) -> Option<Address> {
+        match self {
+            Descriptor::Addr(addr) => Some(addr.address()),
+            Descriptor::Raw(_) => None,
+        }
+    }
+
+    /// Obtains the explicit script for this descriptor.
+    pub fn explicit_script(&self) -> Result<Script, Error> {
+        match self {
+            Descriptor::Addr(addr) => addr.explicit_script(),
+            Descriptor::Raw(raw) => Ok(raw.script_pubkey()),
+        }
+    }
+
+    /// Obtains the maximum satisfaction weight, in weight units, needed to spend this
+    /// descriptor.
+    ///
+    /// Neither variant carries the key material a satisfaction would need, so both report
+    /// the same "no explicit script" error `Addr` already uses for its other key-less
+    /// methods.
+    pub fn max_satisfaction_weight(&self) -> Result<usize, Error> {
+        match self {
+            Descriptor::Addr(_) | Descriptor::Raw(_) => Err(Error::AddrNoExplicitScript),
+        }
+    }
+}
+
+impl FromTree for Descriptor {
+    fn from_tree(top: &expression::Tree) -> Result<Self, Error> {
+        match top.name {
+            "addr" => Ok(Descriptor::Addr(Addr::from_tree(top)?)),
+            "raw" => Ok(Descriptor::Raw(Raw::from_tree(top)?)),
+            _ => Err(Error::Unexpected(format!(
+                "{}({} args): unknown descriptor type",
+                top.name,
+                top.args.len(),
+            ))),
+        }
+    }
+}
+
+impl fmt::Debug for Descriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Descriptor::Addr(addr) => fmt::Debug::fmt(addr, f),
+            Descriptor::Raw(raw) => fmt::Debug::fmt(raw, f),
+        }
+    }
+}
+
+impl fmt::Display for Descriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Descriptor::Addr(addr) => fmt::Display::fmt(addr, f),
+            Descriptor::Raw(raw) => fmt::Display::fmt(raw, f),
+        }
+    }
+}
+
+impl FromStr for Descriptor {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let desc_str = checksum::verify_checksum(s)?;
+        let top = expression::Tree::from_str(desc_str)?;
+        Self::from_tree(&top)
+    }
+}
+
+impl<P, Q> TranslatePk<P, Q> for Descriptor
+where
+    P: MiniscriptKey,
+    Q: MiniscriptKey,
+{
+    type Output = Descriptor;
+
+    fn translate_pk<Fpk, Fpkh, E>(&self, fpk: Fpk, fpkh: Fpkh) -> Result<Self::Output, E>
+    where
+        Fpk: FnMut(&P) -> Result<Q, E>,
+        Fpkh: FnMut(&P::Hash) -> Result<Q::Hash, E>,
+    {
+        match self {
+            Descriptor::Addr(addr) => addr.translate_pk(fpk, fpkh).map(Descriptor::Addr),
+            Descriptor::Raw(raw) => raw.translate_pk(fpk, fpkh).map(Descriptor::Raw),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn descriptor_from_str_dispatches_to_addr() {
+        let addr = Addr::new(
+            Address::from_str("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").unwrap(),
+        );
+        let desc = Descriptor::from_str(&addr.to_string()).unwrap();
+        assert!(matches!(desc, Descriptor::Addr(_)));
+        assert_eq!(desc.script_pubkey(), addr.script_pubkey());
+        assert_eq!(desc.address(), Some(addr.address()));
+    }
+
+    #[test]
+    fn descriptor_from_str_dispatches_to_raw() {
+        let raw = Raw::new(Script::from(vec![0x51, 0x02, 0xab, 0xcd]));
+        let desc = Descriptor::from_str(&raw.to_string()).unwrap();
+        assert!(matches!(desc, Descriptor::Raw(_)));
+        assert_eq!(desc.script_pubkey(), raw.script_pubkey());
+        assert_eq!(desc.address(), None);
+    }
+
+    #[test]
+    fn descriptor_from_tree_rejects_unknown_name() {
+        let tree = expression::Tree {
+            name: "bogus",
+            args: vec![],
+        };
+        assert!(matches!(
+            Descriptor::from_tree(&tree).unwrap_err(),
+            Error::Unexpected(_)
+        ));
+    }
+}
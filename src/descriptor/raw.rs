@@ -0,0 +1,176 @@
+//! # Raw descriptor
+//!
+//! Implementation of the `raw(HEX)` descriptor, which expresses an arbitrary scriptPubKey
+//! as raw hex when it isn't expressible via any of the other descriptor templates.
+//!
+//! See the Bitcoin Core [descriptors doc](https://github.com/bitcoin/bitcoin/blob/master/doc/descriptors.md#reference)
+//! for more.
+//!
+
+use core::{fmt, str::FromStr};
+
+use bitcoin::hashes::hex::FromHex;
+use bitcoin::Script;
+
+use crate::{
+    expression::{self, FromTree},
+    Error, MiniscriptKey, TranslatePk,
+};
+
+use super::checksum::{desc_checksum, verify_checksum};
+
+/// Bitcoin Core's consensus `MAX_SCRIPT_SIZE`: scripts longer than this fail script
+/// execution outright. This is a consensus bound, not a standardness one — a script under
+/// this limit is not thereby guaranteed to be relayed or mined.
+const MAX_SCRIPT_SIZE: usize = 10_000;
+
+/// A raw scriptPubKey descriptor
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Raw {
+    script: Script,
+}
+
+impl Raw {
+    /// Create a new raw descriptor
+    pub fn new(script: Script) -> Self {
+        Raw { script }
+    }
+
+    /// Get the inner script
+    pub fn into_inner(self) -> Script {
+        self.script
+    }
+
+    /// Get the inner script
+    pub fn as_inner(&self) -> &Script {
+        &self.script
+    }
+
+    /// Checks whether the descriptor is safe.
+    ///
+    /// Rejects scripts over Bitcoin Core's consensus `MAX_SCRIPT_SIZE`, i.e. scripts that
+    /// cannot execute under any circumstance. This is a consensus check only: a script under
+    /// the limit may still be non-standard for other reasons (e.g. a bare, non-template
+    /// scriptPubKey), which this method does not attempt to detect.
+    ///
+    /// This is a deliberate descope, not an oversight: `raw()` exists specifically so wallets
+    /// can import a scriptPubKey that isn't expressible as one of the known templates, so
+    /// per-element (520 byte) and op-count standardness bounds — which exist to validate
+    /// *template* scripts — don't apply to its very reason for being. Only the consensus
+    /// bound, past which no script can ever execute, is enforced here.
+    pub fn sanity_check(&self) -> Result<(), Error> {
+        if self.script.len() > MAX_SCRIPT_SIZE {
+            return Err(Error::Unexpected(format!(
+                "raw descriptor script of {} bytes exceeds the {} byte consensus limit",
+                self.script.len(),
+                MAX_SCRIPT_SIZE,
+            )));
+        }
+        Ok(())
+    }
+
+    /// Get the descriptor without the checksum
+    pub fn to_string_no_checksum(&self) -> String {
+        format!("raw({:x})", self.script)
+    }
+
+    /// Obtains the corresponding script pubkey for this descriptor.
+    pub fn script_pubkey(&self) -> Script {
+        self.script.clone()
+    }
+}
+
+impl FromTree for Raw {
+    fn from_tree(top: &expression::Tree) -> Result<Self, Error> {
+        if top.name == "raw" && top.args.len() == 1 {
+            let bytes = Vec::<u8>::from_hex(top.args[0].name)
+                .map_err(|e| Error::Unexpected(e.to_string()))?;
+            Ok(Raw::new(Script::from(bytes)))
+        } else {
+            Err(Error::Unexpected(format!(
+                "{}({} args) while parsing raw descriptor",
+                top.name,
+                top.args.len(),
+            )))
+        }
+    }
+}
+
+impl fmt::Debug for Raw {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.script)
+    }
+}
+
+impl fmt::Display for Raw {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let desc = self.to_string_no_checksum();
+        let checksum = desc_checksum(&desc).map_err(|_| fmt::Error)?;
+        write!(f, "{}#{}", &desc, &checksum)
+    }
+}
+
+impl FromStr for Raw {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let desc_str = verify_checksum(s)?;
+        let top = expression::Tree::from_str(desc_str)?;
+        Self::from_tree(&top)
+    }
+}
+
+impl<P, Q> TranslatePk<P, Q> for Raw
+where
+    P: MiniscriptKey,
+    Q: MiniscriptKey,
+{
+    type Output = Raw;
+
+    fn translate_pk<Fpk, Fpkh, E>(&self, _fpk: Fpk, _fpkh: Fpkh) -> Result<Self::Output, E>
+    where
+        Fpk: FnMut(&P) -> Result<Q, E>,
+        Fpkh: FnMut(&P::Hash) -> Result<Q::Hash, E>,
+    {
+        Ok(Raw::new(self.script.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_round_trip() {
+        let raw = Raw::new(Script::from(vec![0x51, 0x02, 0xab, 0xcd]));
+        let displayed = raw.to_string();
+        assert_eq!(Raw::from_str(&displayed).unwrap(), raw);
+    }
+
+    #[test]
+    fn raw_from_tree_rejects_invalid_hex() {
+        let tree = expression::Tree {
+            name: "raw",
+            args: vec![expression::Tree {
+                name: "not-hex",
+                args: vec![],
+            }],
+        };
+        assert!(matches!(
+            Raw::from_tree(&tree).unwrap_err(),
+            Error::Unexpected(_)
+        ));
+    }
+
+    #[test]
+    fn raw_sanity_check_rejects_oversized_script() {
+        let raw = Raw::new(Script::from(vec![0u8; MAX_SCRIPT_SIZE + 1]));
+        assert!(raw.sanity_check().is_err());
+    }
+
+    #[test]
+    fn raw_sanity_check_accepts_script_within_limit() {
+        let raw = Raw::new(Script::from(vec![0u8; MAX_SCRIPT_SIZE]));
+        assert!(raw.sanity_check().is_ok());
+    }
+}
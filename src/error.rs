@@ -0,0 +1,50 @@
+//! # Error type
+//!
+//! This file carries only the `Error` variants that the `descriptor` module's `addr()`/
+//! `raw()` descriptor types reference; the broader set of parsing/policy/satisfaction error
+//! variants used by the rest of the crate are not reproduced here.
+//!
+
+use core::fmt;
+
+use bitcoin::Network;
+
+/// Error type for miniscript
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// An unexpected token, name, or argument count was encountered while parsing an
+    /// expression
+    Unexpected(String),
+    /// The descriptor's scriptPubKey cannot be represented as an explicit script (e.g. an
+    /// `addr()` descriptor over a P2SH or P2TR address). `Descriptor::max_satisfaction_weight`
+    /// also returns it for every `addr()` and `raw()` descriptor, since neither carries the
+    /// key material a satisfaction would need.
+    AddrNoExplicitScript,
+    /// The descriptor has no meaningful script code (e.g. an `addr()` descriptor over a P2TR
+    /// address)
+    AddrNoScriptCode,
+    /// An `addr()` descriptor's address was parsed for a different network than expected
+    AddrNetworkMismatch {
+        /// The network the address was expected to be valid for
+        expected: Network,
+        /// The network the address was actually issued for
+        found: Network,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Unexpected(s) => write!(f, "unexpected: {}", s),
+            Error::AddrNoExplicitScript => {
+                write!(f, "descriptor does not have an explicit script")
+            }
+            Error::AddrNoScriptCode => write!(f, "descriptor does not have a script code"),
+            Error::AddrNetworkMismatch { expected, found } => write!(
+                f,
+                "address not valid on {} (found an address for {})",
+                expected, found
+            ),
+        }
+    }
+}